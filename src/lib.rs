@@ -1,69 +1,498 @@
+use std::convert::TryFrom;
 use std::ops::Add;
 
+use num_traits::{CheckedAdd, NumCast, One};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct ExclusiveRange<T, S> {
     start: T,
     stop: T,
     step: S,
+    done: bool,
+    origin: T,
+    stop_origin: T,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct InclusiveRange<T, S> {
     start: T,
     stop: T,
     step: S,
+    done: bool,
+    origin: T,
+    stop_origin: T,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct UnboundedRange<T, S> {
     start: T,
     step: S,
+    done: bool,
+    origin: T,
+}
+
+impl<T, S> ExclusiveRange<T, S>
+    where T: Clone
+{
+    /// Restarts iteration from the original `start`/`stop` passed to the
+    /// constructor, undoing any progress made by `next`/`next_back`.
+    pub fn reset(&mut self) {
+        self.start = self.origin.clone();
+        self.stop = self.stop_origin.clone();
+        self.done = false;
+    }
+}
+
+impl<T, S> InclusiveRange<T, S>
+    where T: Clone
+{
+    /// Restarts iteration from the original `start`/`stop` passed to the
+    /// constructor, undoing any progress made by `next`/`next_back`.
+    pub fn reset(&mut self) {
+        self.start = self.origin.clone();
+        self.stop = self.stop_origin.clone();
+        self.done = false;
+    }
+}
+
+impl<T, S> UnboundedRange<T, S>
+    where T: Clone
+{
+    /// Restarts iteration from the original `start` passed to the constructor.
+    pub fn reset(&mut self) {
+        self.start = self.origin.clone();
+        self.done = false;
+    }
 }
 
 pub trait StepBy<S> {
-    fn step_by(self, step: S) -> Self;
+    fn by_step(self, step: S) -> Self;
+}
+
+/// Builds an `ExclusiveRange` stepping by `T::one()`, e.g. `range(0, 10)`.
+///
+/// Chain `.by_step(n)` onto the result to pick a different step.
+pub fn range<T>(start: T, stop: T) -> ExclusiveRange<T, T>
+    where T: One + Clone
+{
+    range_step(start, stop, T::one())
+}
+
+/// Builds an `ExclusiveRange` with an explicit `step`.
+pub fn range_step<T, S>(start: T, stop: T, step: S) -> ExclusiveRange<T, S>
+    where T: Clone
+{
+    ExclusiveRange {
+        origin: start.clone(),
+        start: start,
+        stop_origin: stop.clone(),
+        stop: stop,
+        step: step,
+        done: false,
+    }
+}
+
+/// Builds an `InclusiveRange` stepping by `T::one()`, e.g. `range_inclusive(0, 10)`.
+///
+/// Chain `.by_step(n)` onto the result to pick a different step.
+pub fn range_inclusive<T>(start: T, stop: T) -> InclusiveRange<T, T>
+    where T: One + Clone
+{
+    range_step_inclusive(start, stop, T::one())
+}
+
+/// Builds an `InclusiveRange` with an explicit `step`.
+pub fn range_step_inclusive<T, S>(start: T, stop: T, step: S) -> InclusiveRange<T, S>
+    where T: Clone
+{
+    InclusiveRange {
+        origin: start.clone(),
+        start: start,
+        stop_origin: stop.clone(),
+        stop: stop,
+        step: step,
+        done: false,
+    }
+}
+
+/// Builds an `UnboundedRange` stepping by `T::one()`, e.g. `range_from(0)`.
+///
+/// Chain `.by_step(n)` onto the result to pick a different step.
+pub fn range_from<T>(start: T) -> UnboundedRange<T, T>
+    where T: One + Clone
+{
+    UnboundedRange {
+        origin: start.clone(),
+        start: start,
+        step: T::one(),
+        done: false,
+    }
+}
+
+/// Advances a value by a step, reporting `None` instead of overflowing.
+///
+/// Integer types delegate to `num_traits::CheckedAdd` so iteration can stop
+/// cleanly when `start` would wrap past the type's maximum. Floating-point
+/// types have no such overflow condition, so they always succeed.
+pub trait CheckedStep<S> {
+    fn checked_step(&self, step: &S) -> Option<Self> where Self: Sized;
+}
+
+macro_rules! checked_step_int_impl {
+    ($($t:ty)*) => ($(
+        impl CheckedStep<$t> for $t {
+            fn checked_step(&self, step: &$t) -> Option<$t> {
+                CheckedAdd::checked_add(self, step)
+            }
+        }
+    )*)
+}
+
+checked_step_int_impl! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+macro_rules! checked_step_float_impl {
+    ($($t:ty)*) => ($(
+        impl CheckedStep<$t> for $t {
+            fn checked_step(&self, step: &$t) -> Option<$t> {
+                Some(self + step)
+            }
+        }
+    )*)
+}
+
+checked_step_float_impl! { f32 f64 }
+
+/// Tells a range whether its step moves it forward, backward, or not at all,
+/// so `ExclusiveRange`/`InclusiveRange`/`UnboundedRange` can descend when
+/// `step` is negative instead of only ever counting up.
+pub trait StepSign {
+    fn is_negative(&self) -> bool;
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! step_sign_signed_impl {
+    ($($t:ty)*) => ($(
+        impl StepSign for $t {
+            fn is_negative(&self) -> bool {
+                *self < 0 as $t
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0 as $t
+            }
+        }
+    )*)
 }
 
+step_sign_signed_impl! { i8 i16 i32 i64 isize f32 f64 }
+
+macro_rules! step_sign_unsigned_impl {
+    ($($t:ty)*) => ($(
+        impl StepSign for $t {
+            fn is_negative(&self) -> bool {
+                false
+            }
+
+            fn is_zero(&self) -> bool {
+                *self == 0
+            }
+        }
+    )*)
+}
+
+step_sign_unsigned_impl! { u8 u16 u32 u64 usize }
+
 impl<T, S> Iterator for ExclusiveRange<T, S>
-    where T: PartialOrd + Add<S, Output = T> + Copy,
-          S: Copy
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
 {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
-        if self.start < self.stop {
-            let tmp = self.start;
-            self.start = self.start + self.step;
-            Some(tmp)
+        if self.done || self.step.is_zero() {
+            return None;
+        }
+        let in_range = if self.step.is_negative() {
+            self.start > self.stop
         } else {
-            None
+            self.start < self.stop
+        };
+        if !in_range {
+            return None;
+        }
+        let tmp = self.start;
+        match self.start.checked_step(&self.step) {
+            Some(next) => self.start = next,
+            None => self.done = true,
         }
+        Some(tmp)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            remaining_exclusive(self.start, self.stop, self.step)
+        };
+        (len, Some(len))
     }
 }
 
+impl<T, S> ExactSizeIterator for ExclusiveRange<T, S>
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
+{
+}
+
 impl<T, S> Iterator for InclusiveRange<T, S>
-    where T: PartialOrd + Add<S, Output = T> + Copy,
-          S: Copy
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
 {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
-        if self.start <= self.stop {
-            let tmp = self.start;
-            self.start = self.start + self.step;
-            Some(tmp)
+        if self.done || self.step.is_zero() {
+            return None;
+        }
+        let in_range = if self.step.is_negative() {
+            self.start >= self.stop
         } else {
-            None
+            self.start <= self.stop
+        };
+        if !in_range {
+            return None;
         }
+        let tmp = self.start;
+        match self.start.checked_step(&self.step) {
+            Some(next) => self.start = next,
+            None => self.done = true,
+        }
+        Some(tmp)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            remaining_inclusive(self.start, self.stop, self.step)
+        };
+        (len, Some(len))
+    }
+}
+
+impl<T, S> ExactSizeIterator for InclusiveRange<T, S>
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
+{
+}
+
+/// Saturates an exact element count to `usize::MAX` instead of truncating it
+/// when the true count doesn't fit.
+fn clamp_to_usize(count: i128) -> usize {
+    if count > usize::MAX as i128 {
+        usize::MAX
+    } else {
+        count as usize
+    }
+}
+
+/// Backs `remaining_exclusive`/`remaining_inclusive`/`next_back`'s jump to an
+/// arbitrary element, with a different implementation per `Self` depending on
+/// whether it can be counted exactly.
+///
+/// Integer types go through lossless `i128` arithmetic, converting `start`
+/// and `stop` individually before subtracting (computing `(stop - start)`
+/// in `T` first could itself overflow, e.g. `i32::MAX - i32::MIN`) —
+/// `i128` holds any `i64`/`u64`/`usize` value
+/// or span exactly, unlike `f64`, which would start losing precision past
+/// 2^53. Floating-point types fall back to native float division instead:
+/// a fractional step like `0.3` has no exact integer element count to begin
+/// with, and truncating it through `i128` would wrongly treat it as zero.
+trait RangeArith<S>: Sized {
+    fn remaining_exclusive(start: Self, stop: Self, step: S) -> usize;
+    fn remaining_inclusive(start: Self, stop: Self, step: S) -> usize;
+    fn nth_step(start: Self, step: S, count: usize) -> Option<Self>;
+}
+
+macro_rules! range_arith_int_impl {
+    ($($t:ty)*) => ($(
+        impl RangeArith<$t> for $t {
+            fn remaining_exclusive(start: $t, stop: $t, step: $t) -> usize {
+                let span = stop as i128 - start as i128;
+                let step = step as i128;
+                if step == 0 || span == 0 || span.signum() != step.signum() {
+                    return 0;
+                }
+                clamp_to_usize((span.abs() + step.abs() - 1) / step.abs())
+            }
+
+            fn remaining_inclusive(start: $t, stop: $t, step: $t) -> usize {
+                let span = stop as i128 - start as i128;
+                let step = step as i128;
+                if step == 0 {
+                    return 0;
+                }
+                if span == 0 {
+                    return 1;
+                }
+                if span.signum() != step.signum() {
+                    return 0;
+                }
+                clamp_to_usize(span.abs() / step.abs() + 1)
+            }
+
+            fn nth_step(start: $t, step: $t, count: usize) -> Option<$t> {
+                let start = start as i128;
+                let step = step as i128;
+                let count = i128::try_from(count).ok()?;
+                let offset = step.checked_mul(count)?;
+                NumCast::from(start.checked_add(offset)?)
+            }
+        }
+    )*)
+}
+
+range_arith_int_impl! { i8 i16 i32 i64 isize u8 u16 u32 u64 usize }
+
+macro_rules! range_arith_float_impl {
+    ($($t:ty)*) => ($(
+        impl RangeArith<$t> for $t {
+            fn remaining_exclusive(start: $t, stop: $t, step: $t) -> usize {
+                if step == 0.0 {
+                    return 0;
+                }
+                let span = stop - start;
+                if span == 0.0 || span.signum() != step.signum() {
+                    return 0;
+                }
+                clamp_to_usize((span / step).ceil() as i128)
+            }
+
+            fn remaining_inclusive(start: $t, stop: $t, step: $t) -> usize {
+                if step == 0.0 {
+                    return 0;
+                }
+                let span = stop - start;
+                if span == 0.0 {
+                    return 1;
+                }
+                if span.signum() != step.signum() {
+                    return 0;
+                }
+                clamp_to_usize((span / step).floor() as i128 + 1)
+            }
+
+            fn nth_step(start: $t, step: $t, count: usize) -> Option<$t> {
+                Some(start + step * (count as $t))
+            }
+        }
+    )*)
+}
+
+range_arith_float_impl! { f32 f64 }
+
+/// Counts the elements an `ExclusiveRange` with the given bounds would
+/// produce, i.e. the number of `start + k * step` (for `k >= 0`) from `start`
+/// up to but excluding `stop`, walking forward or backward depending on the
+/// sign of `step`. Returns `0` for an empty, zero-step, or wrong-direction
+/// range rather than looping forever.
+fn remaining_exclusive<T, S>(start: T, stop: T, step: S) -> usize
+    where T: RangeArith<S>
+{
+    T::remaining_exclusive(start, stop, step)
+}
+
+/// Same idea as `remaining_exclusive`, but for an inclusive upper bound.
+fn remaining_inclusive<T, S>(start: T, stop: T, step: S) -> usize
+    where T: RangeArith<S>
+{
+    T::remaining_inclusive(start, stop, step)
+}
+
+/// Computes `start + count * step` in one shot, so `next_back` can jump
+/// straight to an arbitrary element (e.g. the last one) in O(1) instead of
+/// walking forward from `start` one step at a time, which would make a
+/// full `.rev().collect()` over n elements O(n^2).
+///
+/// Returns `None` if the arithmetic overflows.
+fn nth_step<T, S>(start: T, step: S, count: usize) -> Option<T>
+    where T: RangeArith<S>
+{
+    T::nth_step(start, step, count)
+}
+
+impl<T, S> DoubleEndedIterator for ExclusiveRange<T, S>
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let n = remaining_exclusive(self.start, self.stop, self.step);
+        if n == 0 {
+            self.done = true;
+            return None;
+        }
+        let last = match nth_step(self.start, self.step, n - 1) {
+            Some(last) => last,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        self.stop = last;
+        Some(last)
+    }
+}
+
+impl<T, S> DoubleEndedIterator for InclusiveRange<T, S>
+    where T: PartialOrd + CheckedStep<S> + RangeArith<S> + Copy,
+          S: StepSign + Copy
+{
+    fn next_back(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let n = remaining_inclusive(self.start, self.stop, self.step);
+        if n == 0 {
+            self.done = true;
+            return None;
+        }
+        let last = match nth_step(self.start, self.step, n - 1) {
+            Some(last) => last,
+            None => {
+                self.done = true;
+                return None;
+            }
+        };
+        if n == 1 {
+            self.done = true;
+        } else {
+            match nth_step(self.start, self.step, n - 2) {
+                Some(stop) => self.stop = stop,
+                None => self.done = true,
+            }
+        }
+        Some(last)
     }
 }
 
 impl<T, S> Iterator for UnboundedRange<T, S>
-    where T: PartialOrd + Add<S, Output = T> + Copy,
-          S: Copy
+    where T: PartialOrd + CheckedStep<S> + Copy,
+          S: StepSign + Copy
 {
     type Item = T;
 
     fn next(&mut self) -> Option<T> {
+        if self.done || self.step.is_zero() {
+            return None;
+        }
         let tmp = self.start;
-        self.start = self.start + self.step;
+        match self.start.checked_step(&self.step) {
+            Some(next) => self.start = next,
+            None => self.done = true,
+        }
         Some(tmp)
     }
 }
@@ -71,7 +500,7 @@ impl<T, S> Iterator for UnboundedRange<T, S>
 impl<T, S> StepBy<S> for ExclusiveRange<T, S>
     where T: Add<S, Output = T>
 {
-    fn step_by(self, step: S) -> Self {
+    fn by_step(self, step: S) -> Self {
         ExclusiveRange { step: step, ..self }
     }
 }
@@ -79,7 +508,7 @@ impl<T, S> StepBy<S> for ExclusiveRange<T, S>
 impl<T, S> StepBy<S> for InclusiveRange<T, S>
     where T: Add<S, Output = T>
 {
-    fn step_by(self, step: S) -> Self {
+    fn by_step(self, step: S) -> Self {
         InclusiveRange { step: step, ..self }
     }
 }
@@ -87,7 +516,7 @@ impl<T, S> StepBy<S> for InclusiveRange<T, S>
 impl<T, S> StepBy<S> for UnboundedRange<T, S>
     where T: Add<S, Output = T>
 {
-    fn step_by(self, step: S) -> Self {
+    fn by_step(self, step: S) -> Self {
         UnboundedRange { step: step, ..self }
     }
 }
@@ -102,8 +531,11 @@ mod test {
     fn test_excl_int_range() {
         let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
             start: 0,
+            origin: 0,
             stop: 3,
+            stop_origin: 3,
             step: 1,
+            done: false,
         };
         assert_eq!(iter.next(), Some(0));
         assert_eq!(iter.next(), Some(1));
@@ -115,8 +547,11 @@ mod test {
     fn test_excl_float_range() {
         let mut iter: ExclusiveRange<f32, f32> = ExclusiveRange {
             start: 0.0,
+            origin: 0.0,
             stop: 1.0,
+            stop_origin: 1.0,
             step: 0.3,
+            done: false,
         };
         assert!((iter.next().unwrap().abs() - 0.0) < EPSILON);
         assert!((iter.next().unwrap().abs() - 0.3) < EPSILON);
@@ -129,8 +564,11 @@ mod test {
     fn test_excl_float_range_on_boundary() {
         let mut iter: ExclusiveRange<f32, f32> = ExclusiveRange {
             start: 0.0,
+            origin: 0.0,
             stop: 0.9,
+            stop_origin: 0.9,
             step: 0.3,
+            done: false,
         };
         assert!((iter.next().unwrap().abs() - 0.0) < EPSILON);
         assert!((iter.next().unwrap().abs() - 0.3) < EPSILON);
@@ -142,8 +580,11 @@ mod test {
     fn test_incl_int_range() {
         let mut iter: InclusiveRange<i32, i32> = InclusiveRange {
             start: 0,
+            origin: 0,
             stop: 3,
+            stop_origin: 3,
             step: 1,
+            done: false,
         };
         assert_eq!(iter.next(), Some(0));
         assert_eq!(iter.next(), Some(1));
@@ -156,8 +597,11 @@ mod test {
     fn test_incl_float_range() {
         let mut iter: InclusiveRange<f32, f32> = InclusiveRange {
             start: 0.0,
+            origin: 0.0,
             stop: 1.0,
+            stop_origin: 1.0,
             step: 0.3,
+            done: false,
         };
         assert!((iter.next().unwrap().abs() - 0.0) < EPSILON);
         assert!((iter.next().unwrap().abs() - 0.3) < EPSILON);
@@ -170,8 +614,11 @@ mod test {
     fn test_incl_float_range_on_boundary() {
         let mut iter: InclusiveRange<f32, f32> = InclusiveRange {
             start: 0.0,
+            origin: 0.0,
             stop: 0.9,
+            stop_origin: 0.9,
             step: 0.3,
+            done: false,
         };
         assert!((iter.next().unwrap().abs() - 0.0) < EPSILON);
         assert!((iter.next().unwrap().abs() - 0.3) < EPSILON);
@@ -184,7 +631,9 @@ mod test {
     fn test_unbound_int_range() {
         let mut iter: UnboundedRange<i32, i32> = UnboundedRange {
             start: 0,
+            origin: 0,
             step: 1,
+            done: false,
         };
         assert_eq!(iter.next(), Some(0));
         assert_eq!(iter.next(), Some(1));
@@ -201,7 +650,9 @@ mod test {
     fn test_unbound_float_range() {
         let mut iter: UnboundedRange<f32, f32> = UnboundedRange {
             start: 0.0,
+            origin: 0.0,
             step: 0.3,
+            done: false,
         };
         assert!((iter.next().unwrap() - 0.0).abs() < EPSILON);
         assert!((iter.next().unwrap() - 0.3).abs() < EPSILON);
@@ -218,13 +669,309 @@ mod test {
     fn test_steps_excl_int_range() {
         let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
                 start: 0,
+                origin: 0,
                 stop: 5,
+                stop_origin: 5,
                 step: 1,
+                done: false,
             }
-            .step_by(2);
+            .by_step(2);
         assert_eq!(iter.next(), Some(0));
         assert_eq!(iter.next(), Some(2));
         assert_eq!(iter.next(), Some(4));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_unbound_int_range_overflow_stops_instead_of_wrapping() {
+        let mut iter: UnboundedRange<i32, i32> = UnboundedRange {
+            start: i32::max_value() - 1,
+            origin: i32::max_value() - 1,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.next(), Some(i32::max_value() - 1));
+        assert_eq!(iter.next(), Some(i32::max_value()));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_excl_int_range_overflow_stops_instead_of_wrapping() {
+        let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: i32::max_value() - 1,
+            origin: i32::max_value() - 1,
+            stop: i32::max_value(),
+            stop_origin: i32::max_value(),
+            step: 2,
+            done: false,
+        };
+        assert_eq!(iter.next(), Some(i32::max_value() - 1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_excl_int_range_next_back() {
+        let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 5,
+            stop_origin: 5,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_incl_int_range_next_back() {
+        let mut iter: InclusiveRange<i32, i32> = InclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 4,
+            stop_origin: 4,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next_back(), Some(1));
+        assert_eq!(iter.next_back(), Some(0));
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_excl_int_range_rev() {
+        let iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 5,
+            stop_origin: 5,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn test_excl_int_range_next_back_jumps_directly_to_last_element() {
+        // A next_back that walked forward from `start` on every call would
+        // make a single next_back() on a huge range as slow as consuming it
+        // entirely. With a direct O(1) computation this returns immediately
+        // regardless of how large the range is.
+        let mut iter: ExclusiveRange<i64, i64> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 2_000_000_000,
+            stop_origin: 2_000_000_000,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.next_back(), Some(1_999_999_999));
+    }
+
+    #[test]
+    fn test_excl_int_range_len() {
+        let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 5,
+            stop_origin: 5,
+            step: 2,
+            done: false,
+        };
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.len(), 2);
+    }
+
+    #[test]
+    fn test_incl_int_range_len() {
+        let iter: InclusiveRange<i32, i32> = InclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 4,
+            stop_origin: 4,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.len(), 5);
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+    }
+
+    #[test]
+    fn test_excl_u64_range_len_beyond_f64_precision() {
+        let iter: ExclusiveRange<u64, u64> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: (1u64 << 60) + 5,
+            stop_origin: (1u64 << 60) + 5,
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.len(), (1usize << 60) + 5);
+    }
+
+    #[test]
+    fn test_len_does_not_overflow_subtracting_in_t() {
+        // Computing (stop - start) in T before converting to i128 would
+        // panic on subtraction overflow here in debug builds (and silently
+        // wrap in release) even though the true span fits comfortably in
+        // i128.
+        let iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: i32::min_value(),
+            origin: i32::min_value(),
+            stop: i32::max_value(),
+            stop_origin: i32::max_value(),
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.len(), u32::max_value() as usize);
+    }
+
+    #[test]
+    fn test_float_range_next_back_does_not_truncate_fractional_step() {
+        // Converting the step through i128 would truncate a fractional step
+        // like 0.3 to 0 and make `len()`/`next_back()` wrongly treat the
+        // range as empty.
+        let mut iter: ExclusiveRange<f32, f32> = ExclusiveRange {
+            start: 0.0,
+            origin: 0.0,
+            stop: 1.0,
+            stop_origin: 1.0,
+            step: 0.3,
+            done: false,
+        };
+        assert_eq!(iter.len(), 4);
+        assert!((iter.next_back().unwrap() - 0.9).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_excl_int_range_descending() {
+        let iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: 10,
+            origin: 10,
+            stop: 0,
+            stop_origin: 0,
+            step: -2,
+            done: false,
+        };
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 8, 6, 4, 2]);
+    }
+
+    #[test]
+    fn test_incl_int_range_descending() {
+        let iter: InclusiveRange<i32, i32> = InclusiveRange {
+            start: 10,
+            origin: 10,
+            stop: 0,
+            stop_origin: 0,
+            step: -2,
+            done: false,
+        };
+        assert_eq!(iter.collect::<Vec<_>>(), vec![10, 8, 6, 4, 2, 0]);
+    }
+
+    #[test]
+    fn test_unbound_int_range_descending() {
+        let mut iter: UnboundedRange<i32, i32> = UnboundedRange {
+            start: 10,
+            origin: 10,
+            step: -3,
+            done: false,
+        };
+        assert_eq!(iter.next(), Some(10));
+        assert_eq!(iter.next(), Some(7));
+        assert_eq!(iter.next(), Some(4));
+    }
+
+    #[test]
+    fn test_excl_int_range_zero_step_yields_nothing() {
+        let mut iter: ExclusiveRange<i32, i32> = ExclusiveRange {
+            start: 0,
+            origin: 0,
+            stop: 5,
+            stop_origin: 5,
+            step: 0,
+            done: false,
+        };
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_range_constructor() {
+        assert_eq!(range(0, 3).collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_range_inclusive_constructor() {
+        assert_eq!(range_inclusive(0, 3).collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_range_from_constructor() {
+        let mut iter = range_from(0);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+    }
+
+    #[test]
+    fn test_range_step_builder() {
+        assert_eq!(range(0, 10).by_step(2).collect::<Vec<_>>(), vec![0, 2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn test_range_step_inclusive_constructor() {
+        assert_eq!(range_step_inclusive(0, 6, 2).collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_clone_and_eq() {
+        let iter = range(0, 5);
+        let snapshot = iter.clone();
+        assert_eq!(iter, snapshot);
+        assert_eq!(iter.collect::<Vec<_>>(), snapshot.collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_reset_restarts_from_original_start() {
+        let mut iter = range(0, 5);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+        iter.reset();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next(), Some(1));
+    }
+
+    #[test]
+    fn test_reset_restores_stop_after_next_back() {
+        let mut iter = range(0, 5);
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        iter.reset();
+        assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reset_after_overflow_marks_not_done() {
+        let mut iter: UnboundedRange<i32, i32> = UnboundedRange {
+            start: i32::max_value(),
+            origin: i32::max_value(),
+            step: 1,
+            done: false,
+        };
+        assert_eq!(iter.next(), Some(i32::max_value()));
+        assert_eq!(iter.next(), None);
+        iter.reset();
+        assert_eq!(iter.next(), Some(i32::max_value()));
+    }
 }